@@ -75,3 +75,138 @@ pub(crate) fn mul_wide_u128<const LIMBS: usize>(
         Uint::<{ LIMBS }>::from_words(hi.try_into().unwrap()),
     )
 }
+
+/// Computes the exact product of two 256-bit (8-word) chunks as a 512-bit (16-word) result,
+/// by abusing `sys_bigint` with a zero modulus the same way [`mul_wide_u128`] does.
+///
+/// The critical invariant relied on here is that the accelerator, when given a zero modulus,
+/// writes the *full, unreduced* 512-bit product into the output buffer rather than the
+/// 256-bit modular product `sys_bigint`'s signature otherwise implies.
+#[inline(always)]
+fn mul_wide_u256(
+    a: &[u32; BIGINT_WIDTH_WORDS],
+    b: &[u32; BIGINT_WIDTH_WORDS],
+) -> [u32; 2 * BIGINT_WIDTH_WORDS] {
+    unsafe {
+        let mut out = core::mem::MaybeUninit::<[u32; 2 * BIGINT_WIDTH_WORDS]>::uninit();
+        sys_bigint(
+            out.as_mut_ptr() as *mut [u32; BIGINT_WIDTH_WORDS],
+            OP_MULTIPLY,
+            a.as_ptr() as *const [u32; BIGINT_WIDTH_WORDS],
+            b.as_ptr() as *const [u32; BIGINT_WIDTH_WORDS],
+            &[0u32; BIGINT_WIDTH_WORDS],
+        );
+        out.assume_init()
+    }
+}
+
+/// Adds a 512-bit partial product (`value`) into a logical `2 * LIMBS`-word accumulator, split
+/// across the `lo` and `hi` word arrays, starting at word index `offset`, propagating the carry
+/// through as many subsequent words as necessary (including across the `lo`/`hi` boundary).
+#[inline(always)]
+fn accumulate_at<const LIMBS: usize>(
+    lo: &mut [u32; LIMBS],
+    hi: &mut [u32; LIMBS],
+    offset: usize,
+    value: &[u32],
+) {
+    fn slot<const LIMBS: usize>(
+        index: usize,
+        lo: &mut [u32; LIMBS],
+        hi: &mut [u32; LIMBS],
+    ) -> &mut u32 {
+        if index < LIMBS {
+            &mut lo[index]
+        } else {
+            &mut hi[index - LIMBS]
+        }
+    }
+
+    let mut carry = 0u64;
+    for (i, &word) in value.iter().enumerate() {
+        let index = offset + i;
+        if index >= 2 * LIMBS {
+            debug_assert_eq!(word, 0, "accelerated mul_wide overflowed its output width");
+            continue;
+        }
+        let target = slot(index, lo, hi);
+        let sum = *target as u64 + word as u64 + carry;
+        *target = sum as u32;
+        carry = sum >> 32;
+    }
+
+    let mut index = offset + value.len();
+    while carry > 0 && index < 2 * LIMBS {
+        let target = slot(index, lo, hi);
+        let sum = *target as u64 + carry;
+        *target = sum as u32;
+        carry = sum >> 32;
+        index += 1;
+    }
+}
+
+/// Computes the exact wide product of two `Uint<LIMBS>` values, for any `LIMBS` that is a
+/// multiple of the accelerator's native 256-bit (8-word) width.
+///
+/// This generalizes [`mul_wide_u128`] to arbitrary widths by decomposing both operands into
+/// 256-bit chunks and performing schoolbook accumulation of the partial products, each of which
+/// is computed with a single zero-modulus `sys_bigint` call via [`mul_wide_u256`]. This lets
+/// routines like `into_montgomery_form` accelerate moduli wider than 256 bits instead of
+/// falling back entirely to the pure-Rust `Uint::mul_wide`.
+pub(crate) fn mul_wide<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    b: &Uint<LIMBS>,
+) -> (Uint<LIMBS>, Uint<LIMBS>) {
+    // Assert at compile time that our width is a whole number of 256-bit chunks.
+    assert!(LIMBS % BIGINT_WIDTH_WORDS == 0);
+    let chunks = LIMBS / BIGINT_WIDTH_WORDS;
+
+    let a_words = a.as_words();
+    let b_words = b.as_words();
+
+    let mut lo = [0u32; LIMBS];
+    let mut hi = [0u32; LIMBS];
+
+    for i in 0..chunks {
+        let a_chunk: [u32; BIGINT_WIDTH_WORDS] =
+            a_words[i * BIGINT_WIDTH_WORDS..(i + 1) * BIGINT_WIDTH_WORDS]
+                .try_into()
+                .unwrap();
+
+        for j in 0..chunks {
+            let b_chunk: [u32; BIGINT_WIDTH_WORDS] =
+                b_words[j * BIGINT_WIDTH_WORDS..(j + 1) * BIGINT_WIDTH_WORDS]
+                    .try_into()
+                    .unwrap();
+
+            let partial_product = mul_wide_u256(&a_chunk, &b_chunk);
+            accumulate_at(&mut lo, &mut hi, (i + j) * BIGINT_WIDTH_WORDS, &partial_product);
+        }
+    }
+
+    (Uint::<LIMBS>::from_words(lo), Uint::<LIMBS>::from_words(hi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mul_wide;
+    use crate::U512;
+
+    /// The accelerated `mul_wide`, run on two 512-bit (16-word, multi-chunk) operands with
+    /// non-zero low *and* high 256-bit chunks, must agree with the pure-Rust `Uint::mul_wide`
+    /// it is meant to replace. Using `U512::MAX` and `U512::MAX - 1` forces every cross-chunk partial
+    /// product (`i != j`) to be non-zero and every carry, including ones that cross the
+    /// `lo`/`hi` boundary, to actually fire — the part of `accumulate_at` that a single-chunk
+    /// (zero high chunk) operand would never exercise.
+    #[test]
+    fn mul_wide_matches_software_mul_wide() {
+        let a = U512::MAX;
+        let b = U512::MAX.wrapping_sub(&U512::from_u64(1));
+
+        let (lo, hi) = mul_wide(&a, &b);
+        let (expected_lo, expected_hi) = a.mul_wide(&b);
+
+        assert_eq!(lo, expected_lo);
+        assert_eq!(hi, expected_hi);
+    }
+}
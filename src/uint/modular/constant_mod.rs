@@ -29,6 +29,8 @@ mod const_mul;
 mod const_neg;
 /// Exponentiation of residues with a constant modulus
 mod const_pow;
+/// Square roots of residues with a constant modulus
+mod const_sqrt;
 /// Subtractions between residues with a constant modulus
 mod const_sub;
 
@@ -58,6 +60,39 @@ pub trait ResidueParams<const LIMBS: usize>:
     /// The lowest limbs of -(MODULUS^-1) mod R
     // We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
     const MOD_NEG_INV: Limb;
+
+    /// The 2-adicity of `MODULUS - 1`, i.e. the largest `k` such that `2^k` divides `MODULUS - 1`.
+    ///
+    /// Used together with [`ResidueParams::Q`] and [`ResidueParams::ROOT_OF_UNITY`] to seed the
+    /// Tonelli–Shanks square root algorithm in [`Residue::sqrt`].
+    ///
+    /// Defaults to `0`, which is not a valid 2-adicity for any odd modulus; moduli that want to
+    /// support `sqrt` must override this (along with [`ResidueParams::Q`] and
+    /// [`ResidueParams::ROOT_OF_UNITY`]) with the values for their specific `MODULUS`.
+    /// `impl_modulus!` does not compute these, so for a prime modulus defined through it `sqrt`
+    /// returns a root only for `0` and `1`, and `None` for every other input.
+    const S: u32 = 0;
+    /// The odd part of `MODULUS - 1`, such that `MODULUS - 1 = Q * 2^S`.
+    ///
+    /// See the "unsupported by default" note on [`ResidueParams::S`].
+    const Q: Uint<LIMBS> = Self::MODULUS;
+    /// A fixed quadratic non-residue `z` raised to the power [`ResidueParams::Q`], i.e. `z^Q`.
+    ///
+    /// See the "unsupported by default" note on [`ResidueParams::S`].
+    const ROOT_OF_UNITY: Uint<LIMBS> = Uint::<LIMBS>::ONE;
+
+    /// Whether `MODULUS` is prime.
+    ///
+    /// When set to `true` in a RISC Zero zkVM build, a 256-bit `Residue`'s multiplicative inverse
+    /// is computed via Fermat's little theorem (`x^(MODULUS - 2) mod MODULUS`) by
+    /// square-and-multiply instead of the generic, data-dependent binary-GCD `inv_odd_mod`,
+    /// since every step becomes a single accelerated `modmul_u256` syscall. Other builds and
+    /// other widths ignore it and always use `inv_odd_mod`.
+    ///
+    /// Defaults to `false`. `impl_modulus!` does not yet set this for moduli it could prove
+    /// prime, so this fast path is currently only reachable by implementing `ResidueParams`
+    /// directly and overriding this constant.
+    const MODULUS_IS_PRIME: bool = false;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -122,6 +157,21 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             };
         }
 
+        // For moduli wider than 256 bits but a whole number of 256-bit chunks, the wide
+        // multiplication itself can still be accelerated.
+        #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+        if LIMBS > risc0::BIGINT_WIDTH_WORDS && LIMBS % risc0::BIGINT_WIDTH_WORDS == 0 {
+            let product = risc0::mul_wide(integer, &MOD::R2);
+            return Self {
+                montgomery_form: montgomery_reduction::<LIMBS>(
+                    &product,
+                    &MOD::MODULUS,
+                    MOD::MOD_NEG_INV,
+                ),
+                phantom: PhantomData,
+            };
+        }
+
         let product = integer.mul_wide(&MOD::R2);
         let montgomery_form =
             montgomery_reduction::<LIMBS>(&product, &MOD::MODULUS, MOD::MOD_NEG_INV);
@@ -0,0 +1,29 @@
+use core::marker::PhantomData;
+
+use subtle::CtOption;
+
+use crate::modular::inv::inv_montgomery_form;
+
+use super::{Residue, ResidueParams};
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
+    /// Computes the multiplicative inverse of this residue, if it exists (i.e. if `self` is
+    /// coprime to `MOD::MODULUS`).
+    pub fn invert(&self) -> CtOption<Self> {
+        let (montgomery_form, is_some) = inv_montgomery_form(
+            &self.montgomery_form,
+            &MOD::MODULUS,
+            &MOD::R3,
+            MOD::MOD_NEG_INV,
+            &MOD::R,
+            MOD::MODULUS_IS_PRIME,
+        );
+
+        let value = Self {
+            montgomery_form,
+            phantom: PhantomData,
+        };
+
+        CtOption::new(value, is_some.into())
+    }
+}
@@ -0,0 +1,113 @@
+use subtle::{ConstantTimeEq, CtOption};
+
+use crate::Uint;
+
+use super::{Residue, ResidueParams};
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
+    /// Computes the square root of `self` modulo `MOD::MODULUS`, returning `None` (via the
+    /// returned `CtOption`) if `self` is not a quadratic residue.
+    ///
+    /// This uses the Tonelli–Shanks algorithm, seeded by the precomputed
+    /// [`ResidueParams::S`], [`ResidueParams::Q`] and [`ResidueParams::ROOT_OF_UNITY`],
+    /// mirroring the field square-root support exposed by Montgomery field types such as
+    /// those in `pasta_curves`. The initial exponentiations go through [`Residue::pow`] and the
+    /// loop's squarings and multiplications through `Residue` multiplication, both of which in
+    /// the zkVM bottom out in the accelerated `modmul_u256` syscall, making this dramatically
+    /// cheaper than a host-side big-integer square root.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        if bool::from(self.ct_eq(&Self::ZERO)) {
+            return CtOption::new(Self::ZERO, 1u8.into());
+        }
+
+        let mut m = MOD::S;
+        let mut c = Self::new(&MOD::ROOT_OF_UNITY);
+        let mut t = self.pow(&MOD::Q);
+        let mut r = self.pow(&((MOD::Q >> 1) + Uint::<LIMBS>::ONE));
+
+        while !bool::from(t.ct_eq(&Self::ONE)) {
+            // Find the least `i` in `1..m` such that `t^(2^i) == 1`. By Fermat's little
+            // theorem `t^(2^m) == 1` always holds, so if no smaller `i` is found by the time
+            // `i` reaches `m`, `self` is not a quadratic residue: reject it here rather than
+            // underflowing `m - i - 1` below.
+            let mut i = 1;
+            let mut t2i = t * t;
+            while i < m && !bool::from(t2i.ct_eq(&Self::ONE)) {
+                t2i = t2i * t2i;
+                i += 1;
+            }
+            if i >= m {
+                return CtOption::new(Self::ZERO, 0u8.into());
+            }
+
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = b * b;
+            }
+
+            m = i;
+            c = b * b;
+            t = t * b * b;
+            r = r * b;
+        }
+
+        let is_root = (r * r).ct_eq(self);
+        CtOption::new(r, is_root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Limb, Uint};
+
+    use super::super::ResidueParams;
+    use super::Residue;
+
+    /// A hand-rolled `ResidueParams` fixture for the modulus `p = 7` (single 32-bit limb),
+    /// standing in for `impl_modulus!` until it computes `S`/`Q`/`ROOT_OF_UNITY` itself.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    struct Mod7;
+
+    impl ResidueParams<1> for Mod7 {
+        const LIMBS: usize = 1;
+        const MODULUS: Uint<1> = Uint::<1>::from_u32(7);
+        const R: Uint<1> = Uint::<1>::from_u32(4);
+        const R2: Uint<1> = Uint::<1>::from_u32(2);
+        const R3: Uint<1> = Uint::<1>::from_u32(1);
+        const MOD_NEG_INV: Limb = Limb(1_227_133_513);
+        // MODULUS - 1 = 6 = 3 * 2^1
+        const S: u32 = 1;
+        const Q: Uint<1> = Uint::<1>::from_u32(3);
+        // 3 is a non-residue mod 7, and 3^Q = 3^3 mod 7 = 6.
+        const ROOT_OF_UNITY: Uint<1> = Uint::<1>::from_u32(6);
+    }
+
+    type R7 = Residue<Mod7, 1>;
+
+    #[test]
+    fn sqrt_of_zero_is_zero() {
+        let sqrt = R7::new(&Uint::<1>::from_u32(0)).sqrt();
+        assert!(bool::from(sqrt.is_some()));
+        assert_eq!(sqrt.unwrap().retrieve(), Uint::<1>::from_u32(0));
+    }
+
+    #[test]
+    fn sqrt_of_residue_is_a_square_root() {
+        // 2 is a quadratic residue mod 7 (3^2 == 4^2 == 2 mod 7).
+        let two = R7::new(&Uint::<1>::from_u32(2));
+        let sqrt = two.sqrt();
+        assert!(bool::from(sqrt.is_some()));
+
+        let root = sqrt.unwrap();
+        assert_eq!((root * root).retrieve(), two.retrieve());
+    }
+
+    #[test]
+    fn sqrt_of_non_residue_is_rejected() {
+        // 3 is *not* a quadratic residue mod 7. Before the fix, this hit `m = 1, i = 1` and
+        // underflowed `m - i - 1` instead of returning `None`.
+        let three = R7::new(&Uint::<1>::from_u32(3));
+        let sqrt = three.sqrt();
+        assert!(!bool::from(sqrt.is_some()));
+    }
+}
@@ -3,13 +3,28 @@ use crate::{modular::mul::mul_montgomery_form, CtChoice, Limb, Uint};
 #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
 use risc0_zkvm_platform::syscall::bigint;
 
+#[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+use crate::risc0;
+
+#[allow(clippy::too_many_arguments)]
 pub fn inv_montgomery_form<const LIMBS: usize>(
     x: &Uint<LIMBS>,
     modulus: &Uint<LIMBS>,
     r3: &Uint<LIMBS>,
     mod_neg_inv: Limb,
     r_inv: &Uint<LIMBS>,
+    modulus_is_prime: bool,
 ) -> (Uint<LIMBS>, CtChoice) {
+    // Only the zkVM path below actually branches on primality; on a host build, reference it
+    // here so it isn't flagged as unused.
+    #[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+    let _ = modulus_is_prime;
+
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    if modulus_is_prime && LIMBS == risc0::BIGINT_WIDTH_WORDS {
+        return inv_prime_montgomery_form(x, modulus);
+    }
+
     let (inverse, is_some) = x.inv_odd_mod(modulus);
 
     #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
@@ -22,3 +37,27 @@ pub fn inv_montgomery_form<const LIMBS: usize>(
         is_some,
     )
 }
+
+/// Computes `x^(modulus - 2) mod modulus` by square-and-multiply, for prime `modulus`.
+///
+/// This is an alternative to the binary-GCD-style `inv_odd_mod`, which is full of
+/// data-dependent branches that are expensive to prove cycle-by-cycle in the zkVM. Here, every
+/// squaring and multiply is a single accelerated `modmul_u256` syscall. As with the rest of the
+/// 256-bit residue path, values are left in standard (non-Montgomery) form.
+#[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+fn inv_prime_montgomery_form<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+) -> (Uint<LIMBS>, CtChoice) {
+    let exponent = modulus.wrapping_sub(&Uint::<LIMBS>::from(2u8));
+
+    let mut result = Uint::<LIMBS>::ONE;
+    for i in (0..Uint::<LIMBS>::BITS).rev() {
+        result = risc0::modmul_u256(&result, &result, modulus);
+        if bool::from(exponent.bit(i)) {
+            result = risc0::modmul_u256(&result, x, modulus);
+        }
+    }
+
+    (result, x.is_nonzero())
+}
@@ -17,6 +17,15 @@ pub(crate) fn into_montgomery_form<const LIMBS: usize>(
         return risc0::modmul_u256(a, &Uint::<LIMBS>::ONE, modulus);
     }
 
+    // For moduli wider than 256 bits but a whole number of 256-bit chunks, the wide
+    // multiplication itself can still be accelerated, even though the final reduction falls
+    // back to the pure-Rust `montgomery_reduction`.
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    if LIMBS > risc0::BIGINT_WIDTH_WORDS && LIMBS % risc0::BIGINT_WIDTH_WORDS == 0 {
+        let product = risc0::mul_wide(a, r2);
+        return montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv);
+    }
+
     let product = a.mul_wide(r2);
     montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv)
 }
@@ -0,0 +1,269 @@
+use core::fmt::Debug;
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::{Limb, Uint, Word};
+
+use super::{inv::inv_montgomery_form, reduction::montgomery_reduction, Retrieve};
+
+#[cfg(feature = "rand_core")]
+use crate::{rand_core::CryptoRngCore, NonZero, Random, RandomMod};
+
+#[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+use crate::risc0;
+
+/// The parameters to efficiently go to and from the Montgomery form for a given odd modulus
+/// chosen at runtime.
+///
+/// This is the runtime-modulus counterpart to
+/// [`ResidueParams`](super::constant_mod::ResidueParams): where that trait's constants are
+/// baked in at compile time via `impl_modulus!`, `DynResidueParams` computes the equivalent
+/// values with [`DynResidueParams::new`] so that moduli chosen at runtime (e.g. RSA or ECDSA
+/// keys) can still be used with [`DynResidue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynResidueParams<const LIMBS: usize> {
+    /// The constant modulus
+    modulus: Uint<LIMBS>,
+    /// Parameter used in Montgomery reduction
+    r: Uint<LIMBS>,
+    /// R^2, used to move into Montgomery form
+    r2: Uint<LIMBS>,
+    /// R^3, used to perform a multiplicative inverse
+    r3: Uint<LIMBS>,
+    /// The lowest limbs of -(MODULUS^-1) mod R
+    mod_neg_inv: Limb,
+}
+
+impl<const LIMBS: usize> DynResidueParams<LIMBS> {
+    /// Instantiates a new set of `DynResidueParams` representing the given `modulus`, which
+    /// must be odd.
+    pub fn new(modulus: &Uint<LIMBS>) -> Self {
+        let r = Uint::MAX.const_rem(modulus).0.wrapping_add(&Uint::ONE);
+        let r2 = Uint::const_rem_wide(r.square_wide(), modulus).0;
+        let mod_neg_inv = Limb(Word::MIN.wrapping_sub(modulus.inv_mod2k(Word::BITS as usize).limbs[0].0));
+        let r3 = montgomery_reduction::<LIMBS>(&r2.square_wide(), modulus, mod_neg_inv);
+
+        Self {
+            modulus: *modulus,
+            r,
+            r2,
+            r3,
+            mod_neg_inv,
+        }
+    }
+}
+
+/// A residue mod `MOD`, represented using `LIMBS` limbs. The modulus of this residue is chosen
+/// at runtime, via a [`DynResidueParams`] instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynResidue<const LIMBS: usize> {
+    montgomery_form: Uint<LIMBS>,
+    residue_params: DynResidueParams<LIMBS>,
+}
+
+impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// The representation of 0 mod `MOD`.
+    pub const fn zero(residue_params: DynResidueParams<LIMBS>) -> Self {
+        Self {
+            montgomery_form: Uint::<LIMBS>::ZERO,
+            residue_params,
+        }
+    }
+
+    /// The representation of 1 mod `MOD`.
+    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    pub const fn one(residue_params: DynResidueParams<LIMBS>) -> Self {
+        if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+            // In the RISC Zero zkVM 256-bit residues are represented in standard form, where
+            // one is simply `1`, not `r`.
+            Self {
+                montgomery_form: Uint::<LIMBS>::ONE,
+                residue_params,
+            }
+        } else {
+            Self {
+                montgomery_form: residue_params.r,
+                residue_params,
+            }
+        }
+    }
+
+    /// The representation of 1 mod `MOD`.
+    #[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+    pub const fn one(residue_params: DynResidueParams<LIMBS>) -> Self {
+        Self {
+            montgomery_form: residue_params.r,
+            residue_params,
+        }
+    }
+
+    /// Instantiates a new `DynResidue` that represents this `integer` mod `residue_params`.
+    pub fn new(integer: &Uint<LIMBS>, residue_params: DynResidueParams<LIMBS>) -> Self {
+        #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+        if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+            // As with the constant-modulus `Residue`, leave 256-bit values in standard form,
+            // reduced by passing them through a modmul by one.
+            return Self {
+                montgomery_form: risc0::modmul_u256(
+                    integer,
+                    &Uint::<LIMBS>::ONE,
+                    &residue_params.modulus,
+                ),
+                residue_params,
+            };
+        }
+
+        // For moduli wider than 256 bits but a whole number of 256-bit chunks, the wide
+        // multiplication itself can still be accelerated.
+        #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+        if LIMBS > risc0::BIGINT_WIDTH_WORDS && LIMBS % risc0::BIGINT_WIDTH_WORDS == 0 {
+            let product = risc0::mul_wide(integer, &residue_params.r2);
+            let montgomery_form = montgomery_reduction::<LIMBS>(
+                &product,
+                &residue_params.modulus,
+                residue_params.mod_neg_inv,
+            );
+            return Self {
+                montgomery_form,
+                residue_params,
+            };
+        }
+
+        let product = integer.mul_wide(&residue_params.r2);
+        let montgomery_form = montgomery_reduction::<LIMBS>(
+            &product,
+            &residue_params.modulus,
+            residue_params.mod_neg_inv,
+        );
+
+        Self {
+            montgomery_form,
+            residue_params,
+        }
+    }
+
+    /// Retrieves the integer currently encoded in this `DynResidue`, guaranteed to be reduced.
+    pub fn retrieve(&self) -> Uint<LIMBS> {
+        #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+        if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+            return self.montgomery_form;
+        }
+
+        montgomery_reduction::<LIMBS>(
+            &(self.montgomery_form, Uint::ZERO),
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        )
+    }
+
+    /// Returns the parameter struct used to instantiate this object.
+    pub fn params(&self) -> &DynResidueParams<LIMBS> {
+        &self.residue_params
+    }
+
+    /// Computes the (reduced) product of two residues.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        debug_assert_eq!(self.residue_params, rhs.residue_params);
+
+        #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+        if LIMBS == risc0::BIGINT_WIDTH_WORDS {
+            return Self {
+                montgomery_form: risc0::modmul_u256(
+                    &self.montgomery_form,
+                    &rhs.montgomery_form,
+                    &self.residue_params.modulus,
+                ),
+                residue_params: self.residue_params,
+            };
+        }
+
+        // For moduli wider than 256 bits but a whole number of 256-bit chunks, the wide
+        // multiplication itself can still be accelerated.
+        #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+        if LIMBS > risc0::BIGINT_WIDTH_WORDS && LIMBS % risc0::BIGINT_WIDTH_WORDS == 0 {
+            let product = risc0::mul_wide(&self.montgomery_form, &rhs.montgomery_form);
+            let montgomery_form = montgomery_reduction::<LIMBS>(
+                &product,
+                &self.residue_params.modulus,
+                self.residue_params.mod_neg_inv,
+            );
+            return Self {
+                montgomery_form,
+                residue_params: self.residue_params,
+            };
+        }
+
+        let product = self.montgomery_form.mul_wide(&rhs.montgomery_form);
+        let montgomery_form = montgomery_reduction::<LIMBS>(
+            &product,
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        );
+
+        Self {
+            montgomery_form,
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Computes the multiplicative inverse of this residue, if it exists.
+    ///
+    /// Unlike the constant-modulus `Residue::invert`, this always uses the binary-GCD-style
+    /// `inv_odd_mod`, since a runtime modulus cannot be assumed prime (e.g. an RSA modulus is
+    /// a product of primes).
+    pub fn invert(&self) -> CtOption<Self> {
+        let (montgomery_form, is_some) = inv_montgomery_form(
+            &self.montgomery_form,
+            &self.residue_params.modulus,
+            &self.residue_params.r3,
+            self.residue_params.mod_neg_inv,
+            &self.residue_params.r,
+            false,
+        );
+
+        let value = Self {
+            montgomery_form,
+            residue_params: self.residue_params,
+        };
+
+        CtOption::new(value, is_some.into())
+    }
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for DynResidue<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        debug_assert_eq!(a.residue_params, b.residue_params);
+        DynResidue {
+            montgomery_form: Uint::conditional_select(
+                &a.montgomery_form,
+                &b.montgomery_form,
+                choice,
+            ),
+            residue_params: a.residue_params,
+        }
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeEq for DynResidue<LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ConstantTimeEq::ct_eq(&self.montgomery_form, &other.montgomery_form)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// Generates a random `DynResidue`.
+    pub fn random(rng: &mut impl CryptoRngCore, residue_params: DynResidueParams<LIMBS>) -> Self {
+        Self::new(
+            &Uint::random_mod(rng, &NonZero::from_uint(residue_params.modulus)),
+            residue_params,
+        )
+    }
+}
+
+impl<const LIMBS: usize> Retrieve for DynResidue<LIMBS> {
+    type Output = Uint<LIMBS>;
+    fn retrieve(&self) -> Self::Output {
+        self.retrieve()
+    }
+}